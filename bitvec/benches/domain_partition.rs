@@ -0,0 +1,72 @@
+/*! Benchmarks comparing the domain-partitioned bulk paths added for
+`BitVec::set_elements` and `BitVec::extend_from_bitslice` against a
+per-element loop and a per-bit loop, respectively.
+
+This tree has no `Cargo.toml` to add a `criterion` dev-dependency and a
+matching `[[bench]]` entry to, so this file isn't wired up to run yet.
+Once that's in place, run it with `cargo bench --bench domain_partition`.
+!*/
+
+use bitvec::prelude::*;
+use criterion::{
+	black_box,
+	criterion_group,
+	criterion_main,
+	BatchSize,
+	Criterion,
+};
+
+const LEN: usize = 1 << 16;
+
+/// The behavior `set_elements` replaced: write `element` into every element
+/// of the buffer one at a time, edge elements included, rather than
+/// through the domain-partitioned head/body/tail split.
+fn set_elements_naive(bv: &mut BitVec<Local, u8>, element: u8) {
+	bv.as_mut_slice().iter_mut().for_each(|elt| *elt = element);
+}
+
+fn bench_set_elements(c: &mut Criterion) {
+	let mut group = c.benchmark_group("set_elements");
+
+	group.bench_function("domain_partitioned", |b| {
+		let mut bv = bitvec![Local, u8; 0; LEN];
+		b.iter(|| bv.set_elements(black_box(0xA5)));
+	});
+
+	group.bench_function("per_element_loop", |b| {
+		let mut bv = bitvec![Local, u8; 0; LEN];
+		b.iter(|| set_elements_naive(&mut bv, black_box(0xA5)));
+	});
+
+	group.finish();
+}
+
+fn bench_extend_from_bitslice(c: &mut Criterion) {
+	let mut group = c.benchmark_group("extend_from_bitslice");
+	let src = bitvec![Local, u8; 1; LEN];
+
+	group.bench_function("domain_partitioned", |b| {
+		b.iter_batched(
+			BitVec::<Local, u8>::new,
+			|mut dst| dst.extend_from_bitslice(black_box(&src)),
+			BatchSize::SmallInput,
+		);
+	});
+
+	group.bench_function("per_bit_loop", |b| {
+		b.iter_batched(
+			BitVec::<Local, u8>::new,
+			|mut dst| {
+				for bit in src.iter().by_vals() {
+					dst.push(black_box(bit));
+				}
+			},
+			BatchSize::SmallInput,
+		);
+	});
+
+	group.finish();
+}
+
+criterion_group!(benches, bench_set_elements, bench_extend_from_bitslice);
+criterion_main!(benches);
@@ -22,7 +22,10 @@ resizing, and provide some specializations that cannot safely be done on
 use crate::{
 	access::BitAccess,
 	boxed::BitBox,
-	index::BitIdx,
+	index::{
+		BitIdx,
+		BitMask,
+	},
 	mem::BitMemory,
 	order::{
 		BitOrder,
@@ -33,10 +36,20 @@ use crate::{
 	store::BitStore,
 };
 
-use alloc::vec::Vec;
+use alloc::{
+	collections::TryReserveError as AllocError,
+	vec::Vec,
+};
 
 use core::{
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
 	mem::ManuallyDrop,
+	ops::RangeBounds,
 	ptr::NonNull,
 	slice,
 };
@@ -48,6 +61,72 @@ use wyz::{
 	tap::Tap,
 };
 
+/** The error type produced by the fallible allocation APIs on `BitVec`.
+
+This mirrors the standard library’s own (as yet unstable) fallible-allocation
+error type, with an additional variant for the bit-count-to-element-count
+conversion that `BitVec` must perform before it ever touches the allocator.
+
+# Original
+
+[`alloc::collections::TryReserveError`](https://doc.rust-lang.org/alloc/collections/struct.TryReserveError.html)
+**/
+#[derive(Clone, Eq, PartialEq)]
+pub enum TryReserveError {
+	/// The requested bit-length could not be represented as a count of `T`
+	/// elements without overflowing `usize`.
+	CapacityOverflow,
+	/// The allocator was asked to grow the buffer, and refused.
+	AllocError(AllocError),
+}
+
+impl Debug for TryReserveError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::CapacityOverflow => fmt.write_str("CapacityOverflow"),
+			Self::AllocError(err) => Debug::fmt(err, fmt),
+		}
+	}
+}
+
+impl Display for TryReserveError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::CapacityOverflow => fmt.write_str(
+				"the requested bit-length does not fit in the addressable \
+				 region",
+			),
+			Self::AllocError(err) => Display::fmt(err, fmt),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryReserveError {
+}
+
+/// Converts a bit-length into the number of `M` elements needed to store it,
+/// failing rather than overflowing or wrapping when the bit-length does not
+/// fit in a `usize` count of elements.
+#[inline]
+fn checked_elts<M>(bits: usize) -> Result<usize, TryReserveError>
+where M: BitMemory {
+	let width = M::BITS as usize;
+	bits.checked_add(width - 1)
+		.map(|ceil| ceil / width)
+		.ok_or(TryReserveError::CapacityOverflow)
+}
+
+/// Converts a count of `M` elements into the number of bits they hold,
+/// failing rather than overflowing or wrapping when the element count does
+/// not fit in a `usize` count of bits.
+#[inline]
+fn checked_elts_to_bits<M>(elts: usize) -> Result<usize, TryReserveError>
+where M: BitMemory {
+	elts.checked_mul(M::BITS as usize)
+		.ok_or(TryReserveError::CapacityOverflow)
+}
+
 /** A vector of individual bits, allocated on the heap.
 
 This is a managed, heap-allocated, buffer that contains a `BitSlice` region. It
@@ -168,6 +247,132 @@ where
 		out
 	}
 
+	/// Constructs a new, empty `BitVec`, fallibly allocating space for at
+	/// least `len` live bits up front.
+	///
+	/// # Original
+	///
+	/// [`Vec::try_with_capacity`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.try_with_capacity)
+	/// (work in progress in the standard library)
+	///
+	/// # API Differences
+	///
+	/// Unlike [`with_capacity`], which aborts the process on allocation
+	/// failure, this returns a [`TryReserveError`] so that callers in
+	/// memory-constrained environments can recover.
+	///
+	/// # Parameters
+	///
+	/// - `len`: The number of live bits the returned vector is guaranteed to
+	///   hold without reallocating.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` with `len` bits of capacity, or an error describing why the
+	/// allocation could not be made.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::vec::BitVec;
+	///
+	/// let bv = BitVec::<bitvec::order::Local, usize>::try_with_capacity(50)
+	///     .unwrap();
+	/// assert!(bv.is_empty());
+	/// ```
+	///
+	/// [`TryReserveError`]: struct.TryReserveError.html
+	/// [`with_capacity`]: #method.with_capacity
+	#[inline]
+	pub fn try_with_capacity(len: usize) -> Result<Self, TryReserveError> {
+		let mut out = Self::new();
+		out.try_reserve(len)?;
+		Ok(out)
+	}
+
+	/// Tries to reserve capacity for at least `additional` more live bits to
+	/// be inserted into the vector.
+	///
+	/// Unlike [`reserve`], this will never abort the process; it returns an
+	/// error if the capacity computation overflows `usize` or the allocator
+	/// reports failure.
+	///
+	/// # Original
+	///
+	/// [`Vec::try_reserve`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.try_reserve)
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The number of extra live bits the vector should be
+	///   able to hold without reallocating.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if the buffer now has room for `additional` more bits,
+	/// otherwise the [`TryReserveError`] describing what went wrong.
+	///
+	/// [`TryReserveError`]: struct.TryReserveError.html
+	/// [`reserve`]: #method.reserve
+	pub fn try_reserve(
+		&mut self,
+		additional: usize,
+	) -> Result<(), TryReserveError> {
+		self.try_reserve_with(additional, Vec::try_reserve)
+	}
+
+	/// Tries to reserve capacity for at least `additional` more live bits,
+	/// without over-allocating as `try_reserve` may.
+	///
+	/// # Original
+	///
+	/// [`Vec::try_reserve_exact`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.try_reserve_exact)
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The number of extra live bits the vector should be
+	///   able to hold without reallocating.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if the buffer now has room for `additional` more bits,
+	/// otherwise the [`TryReserveError`] describing what went wrong.
+	///
+	/// [`TryReserveError`]: struct.TryReserveError.html
+	pub fn try_reserve_exact(
+		&mut self,
+		additional: usize,
+	) -> Result<(), TryReserveError> {
+		self.try_reserve_with(additional, Vec::try_reserve_exact)
+	}
+
+	/// Shared plumbing for `try_reserve` and `try_reserve_exact`: computes
+	/// the element count the backing `Vec<T::Mem>` must hold in order to
+	/// cover `additional` more live bits, then drives the allocation through
+	/// the `with_vec` shim so the `Vec`’s own fallible reservation is used.
+	fn try_reserve_with(
+		&mut self,
+		additional: usize,
+		reserve: impl FnOnce(
+			&mut Vec<T::Mem>,
+			usize,
+		) -> Result<(), AllocError>,
+	) -> Result<(), TryReserveError> {
+		let head = self.bitptr().head().value() as usize;
+		let total_bits = head
+			.checked_add(self.len())
+			.and_then(|n| n.checked_add(additional))
+			.ok_or(TryReserveError::CapacityOverflow)?;
+		let total_elts = checked_elts::<T::Mem>(total_bits)?;
+
+		self.with_vec(|vec| {
+			let additional_elts = total_elts.saturating_sub(vec.len());
+			reserve(vec, additional_elts)
+		})
+		.map_err(TryReserveError::AllocError)
+	}
+
 	/// Clones a `&BitSlice` into a `BitVec`.
 	///
 	/// # Original
@@ -218,8 +423,10 @@ where
 	/// This unconditionally writes `element` into each live location in the
 	/// backing buffer, without altering the `BitVec`’s length or capacity.
 	///
-	/// It is unspecified what effects this has on the allocated but dead
-	/// elements in the buffer.
+	/// Any dead bits in the leading or trailing edge elements (because the
+	/// vector’s live region does not begin or end on an element boundary) are
+	/// left untouched; only the elements wholly inside the live region are
+	/// overwritten outright.
 	///
 	/// # Parameters
 	///
@@ -232,16 +439,37 @@ where
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut bv = bitvec![Local, u8; 0; 10];
+	/// let mut bv = bitvec![Msb0, u8; 0; 10];
 	/// assert_eq!(bv.as_slice(), [0, 0]);
 	/// bv.set_elements(0xA5);
-	/// assert_eq!(bv.as_slice(), [0xA5, 0xA5]);
+	/// // Only 2 of the trailing element's 8 bits are live; `set_elements`
+	/// // leaves its other 6, dead, bits alone.
+	/// assert_eq!(bv.as_slice(), [0xA5, 0x80]);
+	///
+	/// // Seed those dead bits with a recognizable pattern, then confirm
+	/// // they survive another call untouched.
+	/// *bv.as_mut_slice().last_mut().unwrap() |= 0b0011_1111;
+	/// bv.set_elements(0x00);
+	/// assert_eq!(bv.as_slice(), [0x00, 0b0011_1111]);
 	/// ```
 	#[inline]
 	pub fn set_elements(&mut self, element: T::Mem) {
-		self.as_mut_slice()
-			.iter_mut()
-			.for_each(|elt| *elt = element.into());
+		match self.domain_mut() {
+			DomainMut::Minor(mask, elem) => {
+				*elem = (*elem & !mask.value()) | (element & mask.value());
+			},
+			DomainMut::Major { head, body, tail } => {
+				if let Some((mask, elem)) = head {
+					*elem =
+						(*elem & !mask.value()) | (element & mask.value());
+				}
+				body.fill(element);
+				if let Some((mask, elem)) = tail {
+					*elem =
+						(*elem & !mask.value()) | (element & mask.value());
+				}
+			},
+		}
 	}
 
 	/// Views the buffer’s contents as a `BitSlice`.
@@ -352,9 +580,6 @@ where
 
 	/// Copies all bits in a `BitSlice` into the `BitVec`.
 	///
-	/// This is provided for API completeness; it has no performance benefits
-	/// compared to use of the [`Extend`] implementation.
-	///
 	/// # Parameters
 	///
 	/// - `&mut self`
@@ -363,19 +588,85 @@ where
 	/// # Behavior
 	///
 	/// `self` is extended by the length of `other`, and then the contents of
-	/// `other` are copied into the newly-allocated end of `self`.
+	/// `other` are copied into the newly-allocated end of `self`. When `other`
+	/// begins at the same bit offset within its element as the end of `self`,
+	/// this goes through the same domain partition as [`set_elements`], so the
+	/// interior of the copy is a `memcpy`; otherwise it is copied bit by bit.
 	///
-	/// [`Extend`]: #impl-Extend<%26'a bool>
+	/// [`set_elements`]: #method.set_elements
 	#[inline]
 	pub fn extend_from_bitslice(&mut self, other: &BitSlice<O, T>) {
 		let len = self.len();
 		let olen = other.len();
-		self.reserve(other.len());
+		self.reserve(olen);
 		unsafe {
 			self.set_len(len + olen);
-			self.get_unchecked_mut(len ..)
 		}
-		.clone_from_bitslice(other);
+		self.clone_from_bitslice(len, other);
+	}
+
+	/// Overwrites the live bits of `self` from `start` onward with the bits
+	/// of `other`, which must have exactly `self.len() - start` bits.
+	///
+	/// This is the fast path behind [`extend_from_bitslice`]: because
+	/// `self`’s buffer is uniquely owned, the fully-live interior elements of
+	/// the destination span can be overwritten in bulk with
+	/// `copy_from_slice` instead of bit-by-bit, and only the (at most two)
+	/// partially-live edge elements need a read-modify-write under a mask.
+	///
+	/// This bulk path only applies when `self` (from `start`) and `other`
+	/// begin at the same bit offset within their respective elements: only
+	/// then does a destination mask also describe the matching bits of the
+	/// source element. When the two sides are out of phase, the domains
+	/// still partition into head/body/tail, but the body runs do not line up
+	/// bit-for-bit, so this falls back to copying bit by bit instead of
+	/// risking a mis-masked read or a length-mismatched `copy_from_slice`.
+	///
+	/// [`extend_from_bitslice`]: #method.extend_from_bitslice
+	fn clone_from_bitslice(&mut self, start: usize, other: &BitSlice<O, T>) {
+		debug_assert_eq!(
+			self.len() - start,
+			other.len(),
+			"clone_from_bitslice can only fill an exactly-sized span"
+		);
+
+		let bits = T::Mem::BITS as usize;
+		let dst_phase =
+			(self.bitptr().head().value() as usize + start) % bits;
+		let src_phase = other.bitptr().head().value() as usize % bits;
+
+		if dst_phase != src_phase {
+			for (idx, bit) in other.iter().by_vals().enumerate() {
+				self.set(start + idx, bit);
+			}
+			return;
+		}
+
+		match (domain_mut(self.as_mut_bitslice(), start), domain(other, 0)) {
+			(
+				DomainMut::Major {
+					head: dh,
+					body: db,
+					tail: dt,
+				},
+				Domain::Major {
+					head: sh,
+					body: sb,
+					tail: st,
+				},
+			) => {
+				copy_masked_edge(dh, sh);
+				db.copy_from_slice(sb);
+				copy_masked_edge(dt, st);
+			},
+			(DomainMut::Minor(mask, dst), Domain::Minor(_, src)) => {
+				*dst = (*dst & !mask.value()) | (*src & mask.value());
+			},
+			_ => unreachable!(
+				"a source and destination of equal length always produce \
+				 domains of the same shape"
+			),
+		}
 	}
 
 	/// Gets the number of elements `T` that contain live bits of the vector.
@@ -455,6 +746,79 @@ where
 		}
 	}
 
+	/// Adopts an ordinary vector of memory elements as a `BitVec`, treating
+	/// every bit of every element as live.
+	///
+	/// This is the dual of [`into_vec`]: it reuses `vec`’s existing
+	/// allocation in place, rather than copying its contents bit-by-bit the
+	/// way [`from_bitslice`] must.
+	///
+	/// # Parameters
+	///
+	/// - `vec`: The vector whose buffer is adopted.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` whose live region is exactly `vec`, bit for bit: `vec[0]`
+	/// becomes the first `T::Mem::BITS` bits, `vec[1]` the next, and so on.
+	///
+	/// # Panics
+	///
+	/// This panics if `vec.len() * T::Mem::BITS` bits would not fit in the
+	/// maximum addressable region. Use [`try_from_vec`] to handle this as an
+	/// error instead.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let vec = vec![0x3Cu8];
+	/// let bv = BitVec::<Local, u8>::from_vec(vec);
+	/// assert_eq!(bv.len(), 8);
+	/// ```
+	///
+	/// [`from_bitslice`]: #method.from_bitslice
+	/// [`into_vec`]: #method.into_vec
+	/// [`try_from_vec`]: #method.try_from_vec
+	#[inline]
+	pub fn from_vec(vec: Vec<T>) -> Self {
+		match Self::try_from_vec(vec) {
+			Ok(bv) => bv,
+			Err(err) => panic!("from_vec: {}", err),
+		}
+	}
+
+	/// Fallible form of [`from_vec`], for callers who cannot guarantee that
+	/// `vec` is short enough to be addressed as a bit-slice.
+	///
+	/// # Parameters
+	///
+	/// - `vec`: The vector whose buffer is adopted.
+	///
+	/// # Returns
+	///
+	/// A `BitVec` adopting `vec`’s buffer without reallocating or copying,
+	/// or a [`TryReserveError`] if `vec.len() * T::Mem::BITS` bits would not
+	/// fit in the maximum addressable region.
+	///
+	/// [`TryReserveError`]: struct.TryReserveError.html
+	/// [`from_vec`]: #method.from_vec
+	pub fn try_from_vec(vec: Vec<T>) -> Result<Self, TryReserveError> {
+		let mut vec = ManuallyDrop::new(vec);
+		let capacity = vec.capacity();
+		let bits = checked_elts_to_bits::<T::Mem>(vec.len())?;
+
+		let bitptr =
+			BitPtr::new(vec.as_mut_ptr() as *mut T::Mem, BitIdx::ZERO, bits)
+				.map_err(|_| TryReserveError::CapacityOverflow)?;
+
+		Ok(Self {
+			pointer: bitptr.to_nonnull(),
+			capacity,
+		})
+	}
+
 	/// Ensures that the live region of the vector’s contents begins at the
 	/// leading edge of the buffer.
 	///
@@ -494,6 +858,20 @@ where
 		self.pointer.as_ptr().pipe(BitPtr::from_bitslice_ptr_mut)
 	}
 
+	/// Partitions the vector’s entire live region into a mutable `DomainMut`.
+	///
+	/// The live region is split into an optional masked *head* element, a
+	/// fully-live *body* run of elements, and an optional masked *tail*
+	/// element. Because `BitVec` owns its buffer with no aliasing, the body
+	/// run may be read or written wholesale (`fill`, `copy_from_slice`, …)
+	/// without touching the two edge elements bit-by-bit; only those edges
+	/// need a read-modify-write under their mask to avoid disturbing dead
+	/// bits.
+	#[inline]
+	fn domain_mut(&mut self) -> DomainMut<'_, T::Mem> {
+		self::domain_mut(self.as_mut_bitslice(), 0)
+	}
+
 	/// Permits a function to modify the `Vec<T>` backing storage of a
 	/// `BitVec<_, T>`.
 	///
@@ -535,15 +913,383 @@ where
 		self.capacity = vec.capacity();
 		out
 	}
+
+	/// Removes all bits for which `pred` returns `true`, yielding them
+	/// through an iterator.
+	///
+	/// Unlike repeatedly calling [`remove`] on matching indices, which is
+	/// quadratic in the number of removals, this performs a single
+	/// read/write pass over the vector.
+	///
+	/// # Original
+	///
+	/// [`Vec::extract_if`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.extract_if)
+	///
+	/// # API Differences
+	///
+	/// The predicate receives both the bit’s original index and its value,
+	/// rather than a reference to it, since a lone `bool` has no address
+	/// worth borrowing.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `pred`: Called once for each live bit the iterator examines, in
+	///   order, with its original index and value. Returning `true` removes
+	///   the bit and yields it from the iterator; returning `false` retains it
+	///   in place. Bits not yet examined when the iterator is dropped are
+	///   retained without being passed to `pred`.
+	///
+	/// # Returns
+	///
+	/// An iterator which lazily drives the removal. Dropping the iterator,
+	/// whether or not it has been fully consumed, finishes compacting the
+	/// vector so that it is left with no gaps.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 0, 1, 1, 0];
+	/// let removed = bv.extract_if(|_, bit| bit).collect::<Vec<_>>();
+	/// assert_eq!(removed, [true, true, true]);
+	/// assert_eq!(bv, bits![0, 0, 0]);
+	/// ```
+	///
+	/// [`remove`]: ../slice/struct.BitSlice.html#method.remove
+	#[inline]
+	pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, O, T, F>
+	where F: FnMut(usize, bool) -> bool {
+		let old_len = self.len();
+		ExtractIf {
+			bitvec: self,
+			pred,
+			idx: 0,
+			write: 0,
+			old_len,
+		}
+	}
+
+	/// Removes the bits in `range` from the vector, yielding them through an
+	/// iterator.
+	///
+	/// # Original
+	///
+	/// [`Vec::drain`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.drain)
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `range`: The span of indices to remove.
+	///
+	/// # Returns
+	///
+	/// An iterator which lazily yields the removed bits. Dropping the
+	/// iterator, whether or not it has been fully consumed, finishes
+	/// removing `range` and shifts the remaining tail down to close the gap.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 0, 1, 1, 0];
+	/// let removed = bv.drain(1 .. 4).collect::<Vec<_>>();
+	/// assert_eq!(removed, [true, false, true]);
+	/// assert_eq!(bv, bits![0, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn drain<R>(&mut self, range: R) -> Drain<'_, O, T>
+	where R: RangeBounds<usize> {
+		let old_len = self.len();
+		let (start, end) = resolve_range(range, old_len);
+		Drain {
+			bitvec: self,
+			start,
+			idx: start,
+			end,
+			tail_start: end,
+			old_len,
+		}
+	}
+
+	/// Removes the bits in `range`, replacing them with the contents of
+	/// `replace_with`, and yields the removed bits through an iterator.
+	///
+	/// `replace_with` need not produce the same number of bits as `range`
+	/// removes; the vector grows or shrinks to fit.
+	///
+	/// # Original
+	///
+	/// [`Vec::splice`](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.splice)
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `range`: The span of indices to remove and replace.
+	/// - `replace_with`: The bits inserted in place of `range`.
+	///
+	/// # Returns
+	///
+	/// An iterator which lazily yields the removed bits. Dropping the
+	/// iterator, whether or not it has been fully consumed, finishes
+	/// removing `range` and writes `replace_with` into the gap it leaves.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 0, 1, 1, 0];
+	/// let removed =
+	///     bv.splice(1 .. 4, [true, true].iter().copied()).collect::<Vec<_>>();
+	/// assert_eq!(removed, [true, false, true]);
+	/// assert_eq!(bv, bits![0, 1, 1, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn splice<R, I>(
+		&mut self,
+		range: R,
+		replace_with: I,
+	) -> Splice<'_, O, T, I::IntoIter>
+	where
+		R: RangeBounds<usize>,
+		I: IntoIterator<Item = bool>,
+	{
+		let old_len = self.len();
+		let (start, end) = resolve_range(range, old_len);
+		Splice {
+			bitvec: self,
+			start,
+			idx: start,
+			end,
+			tail_start: end,
+			old_len,
+			replace_with: Some(replace_with.into_iter()),
+		}
+	}
+}
+
+/// Resolves a [`RangeBounds<usize>`] against a collection of length `len`
+/// into a concrete, validated `start .. end` pair.
+///
+/// # Panics
+///
+/// This panics if `start > end`, or if `end > len`.
+///
+/// [`RangeBounds<usize>`]: https://doc.rust-lang.org/core/ops/trait.RangeBounds.html
+fn resolve_range<R>(range: R, len: usize) -> (usize, usize)
+where R: RangeBounds<usize> {
+	let range = crate::devel::normalize_range(range, len);
+	let (start, end) = (range.start, range.end);
+	crate::devel::assert_range(range, len);
+	(start, end)
+}
+
+/** A read-only, three-way partition of a span of live bits.
+
+The span is always partitioned the same way: an optional partially-live
+*head* element, a run of wholly-live *body* elements, and an optional
+partially-live *tail* element. A span that does not reach a full element at
+all collapses to the single-element `Minor` case.
+**/
+enum Domain<'a, M>
+where M: BitMemory
+{
+	/// The span does not reach a full element; the single partially-live
+	/// element is paired with the mask describing which of its bits belong
+	/// to the span.
+	Minor(BitMask<M>, &'a M),
+	/// The span reaches across one or more element boundaries.
+	Major {
+		/// The first element, masked to the bits that belong to the span, if
+		/// the span does not begin on an element boundary.
+		head: Option<(BitMask<M>, &'a M)>,
+		/// The elements that lie entirely within the span.
+		body: &'a [M],
+		/// The last element, masked to the bits that belong to the span, if
+		/// the span does not end on an element boundary.
+		tail: Option<(BitMask<M>, &'a M)>,
+	},
+}
+
+/// The mutable counterpart to [`Domain`](enum.Domain.html).
+enum DomainMut<'a, M>
+where M: BitMemory
+{
+	/// See [`Domain::Minor`](enum.Domain.html#variant.Minor).
+	Minor(BitMask<M>, &'a mut M),
+	/// See [`Domain::Major`](enum.Domain.html#variant.Major).
+	Major {
+		/// See [`Domain::Major::head`](enum.Domain.html#variant.Major.field.head).
+		head: Option<(BitMask<M>, &'a mut M)>,
+		/// See [`Domain::Major::body`](enum.Domain.html#variant.Major.field.body).
+		body: &'a mut [M],
+		/// See [`Domain::Major::tail`](enum.Domain.html#variant.Major.field.tail).
+		tail: Option<(BitMask<M>, &'a mut M)>,
+	},
+}
+
+/// Partitions the span `start .. slice.len()` of `slice`’s live region.
+fn domain<O, T>(slice: &BitSlice<O, T>, start: usize) -> Domain<'_, T::Mem>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let bitptr = slice.bitptr();
+	let bits = T::Mem::BITS as usize;
+	let base = bitptr.pointer().to_access() as *const T::Mem;
+	let elts = bitptr.elements();
+	let raw = unsafe { slice::from_raw_parts(base, elts) };
+
+	let lo = bitptr.head().value() as usize + start;
+	let hi = bitptr.head().value() as usize + slice.len();
+
+	if hi == lo {
+		return Domain::Major {
+			head: None,
+			body: &raw[.. 0],
+			tail: None,
+		};
+	}
+
+	let (first, last) = (lo / bits, (hi - 1) / bits);
+
+	if first == last {
+		let tail_idx = if hi % bits == 0 {
+			BitIdx::ZERO
+		}
+		else {
+			BitIdx::new((hi % bits) as u8)
+		};
+		let mask = O::mask(BitIdx::new((lo % bits) as u8), tail_idx);
+		return Domain::Minor(mask, &raw[first]);
+	}
+
+	let head = if lo % bits != 0 {
+		let mask = O::mask(BitIdx::new((lo % bits) as u8), BitIdx::ZERO);
+		Some((mask, &raw[first]))
+	}
+	else {
+		None
+	};
+	let tail = if hi % bits != 0 {
+		let mask = O::mask(BitIdx::ZERO, BitIdx::new((hi % bits) as u8));
+		Some((mask, &raw[last]))
+	}
+	else {
+		None
+	};
+
+	let body_lo = if head.is_some() { first + 1 } else { first };
+	let body_hi = if tail.is_some() { last } else { last + 1 };
+	Domain::Major {
+		head,
+		body: &raw[body_lo .. body_hi],
+		tail,
+	}
+}
+
+/// Partitions the span `start .. slice.len()` of `slice`’s live region.
+///
+/// # Safety
+///
+/// This performs unmasked bulk writes to the interior of the span, which is
+/// only sound when `slice` has no other live views into the same memory.
+/// `BitVec` upholds this invariant by construction; this function must not
+/// be used on a `BitSlice` that might alias another handle.
+fn domain_mut<O, T>(
+	slice: &mut BitSlice<O, T>,
+	start: usize,
+) -> DomainMut<'_, T::Mem>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	let bitptr = slice.bitptr();
+	let bits = T::Mem::BITS as usize;
+	let base = bitptr.pointer().to_mut() as *mut T::Mem;
+	let elts = bitptr.elements();
+	let raw = unsafe { slice::from_raw_parts_mut(base, elts) };
+
+	let lo = bitptr.head().value() as usize + start;
+	let hi = bitptr.head().value() as usize + slice.len();
+
+	if hi == lo {
+		return DomainMut::Major {
+			head: None,
+			body: &mut raw[.. 0],
+			tail: None,
+		};
+	}
+
+	let (first, last) = (lo / bits, (hi - 1) / bits);
+
+	if first == last {
+		let tail_idx = if hi % bits == 0 {
+			BitIdx::ZERO
+		}
+		else {
+			BitIdx::new((hi % bits) as u8)
+		};
+		let mask = O::mask(BitIdx::new((lo % bits) as u8), tail_idx);
+		return DomainMut::Minor(mask, &mut raw[first]);
+	}
+
+	let has_head = lo % bits != 0;
+	let has_tail = hi % bits != 0;
+	let body_lo = if has_head { first + 1 } else { first };
+	let body_hi = if has_tail { last } else { last + 1 };
+
+	let (head_tail, body) = raw.split_at_mut(body_lo);
+	let (body, tail_elt) = body.split_at_mut(body_hi - body_lo);
+
+	let head = if has_head {
+		let mask = O::mask(BitIdx::new((lo % bits) as u8), BitIdx::ZERO);
+		Some((mask, &mut head_tail[first]))
+	}
+	else {
+		None
+	};
+	let tail = if has_tail {
+		let mask = O::mask(BitIdx::ZERO, BitIdx::new((hi % bits) as u8));
+		Some((mask, &mut tail_elt[0]))
+	}
+	else {
+		None
+	};
+
+	DomainMut::Major { head, body, tail }
+}
+
+/// Writes the masked bits of `src` into the masked bits of `dst`, leaving
+/// the rest of `dst`’s element untouched. A no-op if either edge is absent.
+fn copy_masked_edge<M>(
+	dst: Option<(BitMask<M>, &mut M)>,
+	src: Option<(BitMask<M>, &M)>,
+) where
+	M: BitMemory,
+{
+	if let (Some((mask, dst)), Some((_, src))) = (dst, src) {
+		*dst = (*dst & !mask.value()) | (*src & mask.value());
+	}
 }
 
 mod api;
+mod io;
 mod iter;
 mod ops;
 mod traits;
 
+pub use io::{
+	BitReader,
+	BitWriter,
+	Endian,
+};
 pub use iter::{
 	Drain,
+	ExtractIf,
 	IntoIter,
 	Splice,
 };
@@ -0,0 +1,312 @@
+/*! A bit-packed integer stream, built on top of [`BitVec`] and [`BitSlice`].
+
+This module gives `BitVec` a first-class role as a field-packing buffer for
+wire and file formats: [`BitWriter`] appends integers of arbitrary width to a
+vector, and [`BitReader`] walks them back off of a slice, without either side
+having to hand-roll the shifting and masking themselves.
+
+[`BitReader`]: struct.BitReader.html
+[`BitSlice`]: ../../slice/struct.BitSlice.html
+[`BitVec`]: ../../vec/struct.BitVec.html
+[`BitWriter`]: struct.BitWriter.html
+!*/
+
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+/// The widest integer field that `BitWriter`/`BitReader` will pack or unpack
+/// in a single call.
+const MAX_WIDTH: usize = u64::BITS as usize;
+
+/** Selects which end of a packed field is written or read first.
+
+This is independent of the `O: BitOrder` type parameter on the `BitVec` or
+`BitSlice` being packed: `Endian` only decides which of a *value*’s bits is
+written first, while `BitOrder` decides which physical bit of a storage
+register the “first” stream position occupies. A value written with
+`Endian::Big` reads back identically regardless of whether the underlying
+buffer uses `Lsb0` or `Msb0` as its `BitOrder`.
+**/
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Endian {
+	/// The value’s most significant live bit is written first.
+	Big,
+	/// The value’s least significant bit is written first.
+	Little,
+}
+
+/** Appends bit-packed integer fields to a [`BitVec`].
+
+This is constructed over a `&mut BitVec`, and each call to [`push_bits`] (or
+one of the `push_u*` conveniences) appends exactly `width` low bits of the
+given value to the end of the vector.
+
+# Type Parameters
+
+- `O`: The ordering used by the destination vector.
+- `T`: The storage type used by the destination vector.
+
+[`BitVec`]: ../struct.BitVec.html
+[`push_bits`]: #method.push_bits
+**/
+pub struct BitWriter<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	bits: &'a mut BitVec<O, T>,
+}
+
+impl<'a, O, T> BitWriter<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Wraps a `BitVec` so that integer fields can be packed onto its end.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The vector that packed fields will be appended to.
+	///
+	/// # Returns
+	///
+	/// A `BitWriter` which appends to `bits`.
+	#[inline]
+	pub fn new(bits: &'a mut BitVec<O, T>) -> Self {
+		Self { bits }
+	}
+
+	/// Appends the `width` low bits of `value` to the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The integer whose low `width` bits are packed.
+	/// - `width`: How many bits of `value` to pack. Must not exceed 64.
+	/// - `endian`: Whether `value`’s most or least significant live bit is
+	///   written first.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than 64.
+	///
+	/// # Behavior
+	///
+	/// `width == 0` is a no-op: nothing is appended, regardless of `value` or
+	/// `endian`.
+	pub fn push_bits(&mut self, value: u64, width: usize, endian: Endian) {
+		assert!(
+			width <= MAX_WIDTH,
+			"cannot pack a field {} bits wide into a 64-bit integer",
+			width,
+		);
+		if width == 0 {
+			return;
+		}
+
+		match endian {
+			Endian::Big => {
+				for shift in (0 .. width).rev() {
+					self.bits.push(value & (1 << shift) != 0);
+				}
+			},
+			Endian::Little => {
+				for shift in 0 .. width {
+					self.bits.push(value & (1 << shift) != 0);
+				}
+			},
+		}
+	}
+
+	/// Appends all 8 bits of `value`.
+	#[inline]
+	pub fn push_u8(&mut self, value: u8, endian: Endian) {
+		self.push_bits(value as u64, 8, endian);
+	}
+
+	/// Appends all 16 bits of `value`.
+	#[inline]
+	pub fn push_u16(&mut self, value: u16, endian: Endian) {
+		self.push_bits(value as u64, 16, endian);
+	}
+
+	/// Appends all 32 bits of `value`.
+	#[inline]
+	pub fn push_u32(&mut self, value: u32, endian: Endian) {
+		self.push_bits(value as u64, 32, endian);
+	}
+
+	/// Appends all 64 bits of `value`.
+	#[inline]
+	pub fn push_u64(&mut self, value: u64, endian: Endian) {
+		self.push_bits(value, 64, endian);
+	}
+}
+
+/** Reads bit-packed integer fields back off of a [`BitSlice`].
+
+This is constructed over a `&BitSlice`, and maintains an internal cursor that
+advances by `width` bits on each call to [`read_bits`].
+
+# Type Parameters
+
+- `O`: The ordering used by the source slice.
+- `T`: The storage type used by the source slice.
+
+[`BitSlice`]: ../../slice/struct.BitSlice.html
+[`read_bits`]: #method.read_bits
+**/
+pub struct BitReader<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	bits: &'a BitSlice<O, T>,
+	cursor: usize,
+}
+
+impl<'a, O, T> BitReader<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Wraps a `BitSlice` so that integer fields can be unpacked off its
+	/// front.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The slice that packed fields will be read from, starting at
+	///   its first bit.
+	///
+	/// # Returns
+	///
+	/// A `BitReader` positioned at the start of `bits`.
+	#[inline]
+	pub fn new(bits: &'a BitSlice<O, T>) -> Self {
+		Self { bits, cursor: 0 }
+	}
+
+	/// Reads `width` bits off of the cursor and assembles them into a
+	/// `u64`, advancing the cursor by `width`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `width`: How many bits to consume. Must not exceed 64.
+	/// - `endian`: Whether the first bit consumed is the most or least
+	///   significant bit of the assembled value. This must match the
+	///   `endian` the field was written with.
+	///
+	/// # Returns
+	///
+	/// The `width`-bit field, right-aligned in a `u64`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` is greater than 64, or if fewer than `width`
+	/// bits remain unread in the slice.
+	pub fn read_bits(&mut self, width: usize, endian: Endian) -> u64 {
+		assert!(
+			width <= MAX_WIDTH,
+			"cannot unpack a field {} bits wide from a 64-bit integer",
+			width,
+		);
+		if width == 0 {
+			return 0;
+		}
+		assert!(
+			width <= self.bits.len() - self.cursor,
+			"not enough bits remaining: need {}, have {}",
+			width,
+			self.bits.len() - self.cursor,
+		);
+
+		let field = &self.bits[self.cursor .. self.cursor + width];
+		self.cursor += width;
+
+		let mut value = 0u64;
+		match endian {
+			Endian::Big => {
+				for bit in field.iter().by_vals() {
+					value = (value << 1) | bit as u64;
+				}
+			},
+			Endian::Little => {
+				for (idx, bit) in field.iter().by_vals().enumerate() {
+					value |= (bit as u64) << idx;
+				}
+			},
+		}
+		value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn round_trips_big_endian_on_lsb0() {
+		let mut bv = BitVec::<Lsb0, u8>::new();
+		BitWriter::new(&mut bv).push_u32(0xDEAD_BEEF, Endian::Big);
+		assert_eq!(BitReader::new(&bv).read_bits(32, Endian::Big), 0xDEAD_BEEF);
+	}
+
+	#[test]
+	fn round_trips_big_endian_on_msb0() {
+		let mut bv = BitVec::<Msb0, u8>::new();
+		BitWriter::new(&mut bv).push_u32(0xDEAD_BEEF, Endian::Big);
+		assert_eq!(BitReader::new(&bv).read_bits(32, Endian::Big), 0xDEAD_BEEF);
+	}
+
+	#[test]
+	fn round_trips_little_endian_on_lsb0() {
+		let mut bv = BitVec::<Lsb0, u8>::new();
+		BitWriter::new(&mut bv).push_u32(0xDEAD_BEEF, Endian::Little);
+		assert_eq!(
+			BitReader::new(&bv).read_bits(32, Endian::Little),
+			0xDEAD_BEEF
+		);
+	}
+
+	#[test]
+	fn round_trips_little_endian_on_msb0() {
+		let mut bv = BitVec::<Msb0, u8>::new();
+		BitWriter::new(&mut bv).push_u32(0xDEAD_BEEF, Endian::Little);
+		assert_eq!(
+			BitReader::new(&bv).read_bits(32, Endian::Little),
+			0xDEAD_BEEF
+		);
+	}
+
+	#[test]
+	fn width_zero_is_a_no_op() {
+		let mut bv = BitVec::<Local, u8>::new();
+		BitWriter::new(&mut bv).push_bits(0xFF, 0, Endian::Big);
+		assert!(bv.is_empty());
+
+		let src = bitvec![Local, u8; 1, 0, 1];
+		let mut reader = BitReader::new(&src);
+		assert_eq!(reader.read_bits(0, Endian::Big), 0);
+		assert_eq!(reader.read_bits(3, Endian::Big), 0b101);
+	}
+
+	#[test]
+	#[should_panic(expected = "cannot pack a field 65 bits wide")]
+	fn push_bits_rejects_overwide_fields() {
+		let mut bv = BitVec::<Local, u8>::new();
+		BitWriter::new(&mut bv).push_bits(0, 65, Endian::Big);
+	}
+
+	#[test]
+	#[should_panic(expected = "cannot unpack a field 65 bits wide")]
+	fn read_bits_rejects_overwide_fields() {
+		let src = bitvec![Local, u8; 0; 70];
+		BitReader::new(&src).read_bits(65, Endian::Big);
+	}
+}
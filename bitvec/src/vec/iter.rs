@@ -0,0 +1,446 @@
+/*! Iterators specific to `BitVec<O, T>`.
+
+This module collects the iterator types that are only produced by, and only
+make sense for, an owned bit-vector: [`Drain`], [`IntoIter`], [`Splice`], and
+[`ExtractIf`]. Borrowing iterators that walk any `BitSlice` region live in the
+`slice` module instead.
+
+[`Drain`]: struct.Drain.html
+[`ExtractIf`]: struct.ExtractIf.html
+[`IntoIter`]: struct.IntoIter.html
+[`Splice`]: struct.Splice.html
+!*/
+
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+	vec::BitVec,
+};
+
+use alloc::vec::Vec;
+
+use core::iter::FusedIterator;
+
+/** A lazy, predicate-driven, in-place filter over a [`BitVec`].
+
+This is constructed by the [`BitVec::extract_if`] method.
+
+# Type Parameters
+
+- `O`: The ordering used by the source vector.
+- `T`: The storage type used by the source vector.
+- `F`: The predicate used to select bits for removal.
+
+[`BitVec`]: ../struct.BitVec.html
+[`BitVec::extract_if`]: ../struct.BitVec.html#method.extract_if
+**/
+#[must_use = "iterators are lazy, and do nothing unless consumed"]
+pub struct ExtractIf<'a, O, T, F>
+where
+	O: BitOrder,
+	T: BitStore,
+	F: FnMut(usize, bool) -> bool,
+{
+	/// The vector being drained and compacted.
+	pub(super) bitvec: &'a mut BitVec<O, T>,
+	/// The predicate used to select bits for removal.
+	pub(super) pred: F,
+	/// The read cursor: the index of the next not-yet-examined bit.
+	pub(super) idx: usize,
+	/// The write cursor: the index at which the next retained bit is placed.
+	pub(super) write: usize,
+	/// The vector’s length when the iterator was created.
+	pub(super) old_len: usize,
+}
+
+impl<'a, O, T, F> Iterator for ExtractIf<'a, O, T, F>
+where
+	O: BitOrder,
+	T: BitStore,
+	F: FnMut(usize, bool) -> bool,
+{
+	type Item = bool;
+
+	fn next(&mut self) -> Option<bool> {
+		while self.idx < self.old_len {
+			let idx = self.idx;
+			let bit = self.bitvec[idx];
+			self.idx += 1;
+
+			if (self.pred)(idx, bit) {
+				return Some(bit);
+			}
+
+			if self.write != idx {
+				self.bitvec.set(self.write, bit);
+			}
+			self.write += 1;
+		}
+		None
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.old_len - self.idx))
+	}
+}
+
+impl<'a, O, T, F> Drop for ExtractIf<'a, O, T, F>
+where
+	O: BitOrder,
+	T: BitStore,
+	F: FnMut(usize, bool) -> bool,
+{
+	/// Compacts the not-yet-examined tail of the vector after the write
+	/// cursor, then shrinks the vector’s length to match.
+	///
+	/// This runs whether or not the iterator was fully consumed, so a caller
+	/// that stops iterating early still leaves the vector in a valid,
+	/// gap-free state.
+	fn drop(&mut self) {
+		let remaining = self.old_len - self.idx;
+		if remaining > 0 && self.write != self.idx {
+			unsafe {
+				self.bitvec
+					.copy_within_unchecked(self.idx .. self.old_len, self.write);
+			}
+		}
+		unsafe {
+			self.bitvec.set_len(self.write + remaining);
+		}
+	}
+}
+
+/** A draining iterator that removes and yields a range of bits from a
+[`BitVec`].
+
+This is constructed by the [`BitVec::drain`] method.
+
+# Type Parameters
+
+- `O`: The ordering used by the source vector.
+- `T`: The storage type used by the source vector.
+
+[`BitVec`]: ../struct.BitVec.html
+[`BitVec::drain`]: ../struct.BitVec.html#method.drain
+**/
+#[must_use = "iterators are lazy, and do nothing unless consumed"]
+pub struct Drain<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The vector being drained.
+	pub(super) bitvec: &'a mut BitVec<O, T>,
+	/// The removed range’s start; also where the tail is shifted down to.
+	pub(super) start: usize,
+	/// The first index, inclusive, of the removed range not yet yielded.
+	pub(super) idx: usize,
+	/// The first index, exclusive, of the removed range not yet yielded.
+	pub(super) end: usize,
+	/// The removed range’s original end, where the untouched tail begins.
+	pub(super) tail_start: usize,
+	/// The vector’s length when the iterator was created.
+	pub(super) old_len: usize,
+}
+
+impl<'a, O, T> Iterator for Drain<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = bool;
+
+	fn next(&mut self) -> Option<bool> {
+		if self.idx >= self.end {
+			return None;
+		}
+		let bit = self.bitvec[self.idx];
+		self.idx += 1;
+		Some(bit)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.end - self.idx;
+		(remaining, Some(remaining))
+	}
+}
+
+impl<'a, O, T> DoubleEndedIterator for Drain<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn next_back(&mut self) -> Option<bool> {
+		if self.idx >= self.end {
+			return None;
+		}
+		self.end -= 1;
+		Some(self.bitvec[self.end])
+	}
+}
+
+impl<'a, O, T> ExactSizeIterator for Drain<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<'a, O, T> FusedIterator for Drain<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<'a, O, T> Drop for Drain<'a, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Closes the gap left by the drained range, then shrinks the vector’s
+	/// length to match.
+	///
+	/// This runs whether or not the iterator was fully consumed, so a caller
+	/// that stops iterating early still leaves the vector in a valid,
+	/// gap-free state.
+	fn drop(&mut self) {
+		let tail_len = self.old_len - self.tail_start;
+		if tail_len > 0 {
+			unsafe {
+				self.bitvec.copy_within_unchecked(
+					self.tail_start .. self.old_len,
+					self.start,
+				);
+			}
+		}
+		unsafe {
+			self.bitvec.set_len(self.start + tail_len);
+		}
+	}
+}
+
+/** An iterator that moves bits out of a [`BitVec`], consuming it by value.
+
+This is constructed by calling `.into_iter()` on a `BitVec` through the
+[`IntoIterator`] trait.
+
+# Type Parameters
+
+- `O`: The ordering used by the source vector.
+- `T`: The storage type used by the source vector.
+
+[`BitVec`]: ../struct.BitVec.html
+[`IntoIterator`]: https://doc.rust-lang.org/core/iter/trait.IntoIterator.html
+**/
+#[must_use = "iterators are lazy, and do nothing unless consumed"]
+pub struct IntoIter<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// The vector being consumed.
+	bitvec: BitVec<O, T>,
+	/// The first index, inclusive, not yet yielded from the front.
+	front: usize,
+	/// The first index, exclusive, not yet yielded from the back.
+	back: usize,
+}
+
+impl<O, T> Iterator for IntoIter<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type Item = bool;
+
+	fn next(&mut self) -> Option<bool> {
+		if self.front >= self.back {
+			return None;
+		}
+		let bit = self.bitvec[self.front];
+		self.front += 1;
+		Some(bit)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.back - self.front;
+		(remaining, Some(remaining))
+	}
+}
+
+impl<O, T> DoubleEndedIterator for IntoIter<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn next_back(&mut self) -> Option<bool> {
+		if self.front >= self.back {
+			return None;
+		}
+		self.back -= 1;
+		Some(self.bitvec[self.back])
+	}
+}
+
+impl<O, T> ExactSizeIterator for IntoIter<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> FusedIterator for IntoIter<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+impl<O, T> IntoIterator for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	type IntoIter = IntoIter<O, T>;
+	type Item = bool;
+
+	fn into_iter(self) -> Self::IntoIter {
+		let back = self.len();
+		IntoIter {
+			bitvec: self,
+			front: 0,
+			back,
+		}
+	}
+}
+
+/** A splicing iterator that removes a range of bits from a [`BitVec`] and
+replaces them with the contents of another iterator.
+
+This is constructed by the [`BitVec::splice`] method. It yields the removed
+bits as it is driven, the same as [`Drain`]; dropping it, whether or not it
+was fully consumed, writes the replacement bits into the gap the removal
+leaves.
+
+# Type Parameters
+
+- `O`: The ordering used by the source vector.
+- `T`: The storage type used by the source vector.
+- `I`: The iterator supplying the replacement bits.
+
+[`BitVec`]: ../struct.BitVec.html
+[`BitVec::splice`]: ../struct.BitVec.html#method.splice
+[`Drain`]: struct.Drain.html
+**/
+#[must_use = "iterators are lazy, and do nothing unless consumed"]
+pub struct Splice<'a, O, T, I>
+where
+	O: BitOrder,
+	T: BitStore,
+	I: Iterator<Item = bool>,
+{
+	/// The vector being spliced.
+	pub(super) bitvec: &'a mut BitVec<O, T>,
+	/// The removed range’s start; also where the replacement is inserted.
+	pub(super) start: usize,
+	/// The first index, inclusive, of the removed range not yet yielded.
+	pub(super) idx: usize,
+	/// The first index, exclusive, of the removed range not yet yielded.
+	pub(super) end: usize,
+	/// The removed range’s original end, where the untouched tail begins.
+	pub(super) tail_start: usize,
+	/// The vector’s length when the iterator was created.
+	pub(super) old_len: usize,
+	/// The bits written into the gap on drop. Wrapped in `Option` only so
+	/// `drop`, which takes `&mut self`, can move it out.
+	pub(super) replace_with: Option<I>,
+}
+
+impl<'a, O, T, I> Iterator for Splice<'a, O, T, I>
+where
+	O: BitOrder,
+	T: BitStore,
+	I: Iterator<Item = bool>,
+{
+	type Item = bool;
+
+	fn next(&mut self) -> Option<bool> {
+		if self.idx >= self.end {
+			return None;
+		}
+		let bit = self.bitvec[self.idx];
+		self.idx += 1;
+		Some(bit)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.end - self.idx;
+		(remaining, Some(remaining))
+	}
+}
+
+impl<'a, O, T, I> DoubleEndedIterator for Splice<'a, O, T, I>
+where
+	O: BitOrder,
+	T: BitStore,
+	I: Iterator<Item = bool>,
+{
+	fn next_back(&mut self) -> Option<bool> {
+		if self.idx >= self.end {
+			return None;
+		}
+		self.end -= 1;
+		Some(self.bitvec[self.end])
+	}
+}
+
+impl<'a, O, T, I> ExactSizeIterator for Splice<'a, O, T, I>
+where
+	O: BitOrder,
+	T: BitStore,
+	I: Iterator<Item = bool>,
+{
+}
+
+impl<'a, O, T, I> FusedIterator for Splice<'a, O, T, I>
+where
+	O: BitOrder,
+	T: BitStore,
+	I: Iterator<Item = bool>,
+{
+}
+
+impl<'a, O, T, I> Drop for Splice<'a, O, T, I>
+where
+	O: BitOrder,
+	T: BitStore,
+	I: Iterator<Item = bool>,
+{
+	/// Finishes removing the requested range, whether or not it was fully
+	/// consumed, then writes the replacement bits into the gap it leaves.
+	fn drop(&mut self) {
+		let tail: Vec<bool> = self.bitvec[self.tail_start .. self.old_len]
+			.iter()
+			.by_vals()
+			.collect();
+		let replace_with: Vec<bool> = match self.replace_with.take() {
+			Some(it) => it.collect(),
+			None => Vec::new(),
+		};
+
+		let new_len = self.start + replace_with.len() + tail.len();
+		if new_len > self.old_len {
+			self.bitvec.reserve(new_len - self.old_len);
+		}
+		unsafe {
+			self.bitvec.set_len(new_len);
+		}
+
+		let mut idx = self.start;
+		for bit in replace_with.into_iter().chain(tail) {
+			self.bitvec.set(idx, bit);
+			idx += 1;
+		}
+	}
+}
@@ -0,0 +1,137 @@
+/*! Unit tests for `BitVec`. !*/
+
+use crate::prelude::*;
+
+#[test]
+fn try_reserve_grows_capacity() {
+	let mut bv = BitVec::<Local, u8>::new();
+	assert!(bv.try_reserve(100).is_ok());
+	assert!(bv.capacity() >= 100);
+}
+
+#[test]
+fn try_with_capacity_preallocates_an_empty_vector() {
+	let bv = BitVec::<Local, u8>::try_with_capacity(50).unwrap();
+	assert!(bv.is_empty());
+	assert!(bv.capacity() >= 50);
+}
+
+#[test]
+fn try_reserve_reports_capacity_overflow() {
+	let mut bv = BitVec::<Local, u8>::new();
+	assert!(bv.try_reserve(usize::MAX).is_err());
+}
+
+#[test]
+fn clone_from_bitslice_handles_phase_mismatch() {
+	let mut bv = BitVec::<Local, u8>::new();
+	bv.extend_from_bitslice(bits![Local, u8; 0, 1, 1]);
+
+	let src = bitvec![Local, u8; 1, 0, 1, 1, 0];
+	bv.extend_from_bitslice(&src[1 ..]);
+
+	assert_eq!(bv, bits![0, 1, 1, 0, 1, 1, 0]);
+}
+
+#[test]
+fn set_elements_handles_register_aligned_minor_span() {
+	let mut bv = bitvec![Local, u8; 0; 8];
+	bv.set_elements(0xA5);
+	assert_eq!(bv.as_slice(), [0xA5]);
+}
+
+#[test]
+fn set_elements_on_empty_vector_is_a_no_op() {
+	let mut bv = BitVec::<Local, u8>::new();
+	bv.set_elements(0xFF);
+	assert!(bv.is_empty());
+}
+
+#[test]
+fn extend_from_bitslice_with_empty_phase_matched_slice_is_a_no_op() {
+	let mut bv = BitVec::<Local, u8>::new();
+	bv.extend_from_bitslice(bits![Local, u8; 0, 1, 1]);
+
+	let empty = &bitvec![Local, u8; 0, 1, 1][3 ..];
+	bv.extend_from_bitslice(empty);
+
+	assert_eq!(bv, bits![0, 1, 1]);
+}
+
+#[test]
+fn extract_if_dropped_early_still_compacts() {
+	let mut bv = bitvec![0, 1, 0, 1, 1, 0];
+	// Yield only the first removed bit, then drop the iterator without
+	// examining the rest of the vector.
+	bv.extract_if(|_, bit| bit).next();
+	assert_eq!(bv, bits![0, 0, 1, 1, 0]);
+}
+
+#[test]
+fn extract_if_predicate_sees_original_indices() {
+	let mut bv = bitvec![0, 1, 0, 1, 1, 0];
+	let mut seen = Vec::new();
+
+	bv.extract_if(|idx, _| {
+		seen.push(idx);
+		false
+	})
+	.for_each(drop);
+
+	assert_eq!(seen, (0 .. 6).collect::<Vec<_>>());
+	assert_eq!(bv, bits![0, 1, 0, 1, 1, 0]);
+}
+
+#[test]
+fn drain_removes_and_yields_a_range() {
+	let mut bv = bitvec![0, 1, 0, 1, 1, 0];
+	let removed = bv.drain(1 .. 4).collect::<Vec<_>>();
+	assert_eq!(removed, [true, false, true]);
+	assert_eq!(bv, bits![0, 1, 0]);
+}
+
+#[test]
+fn drain_dropped_early_still_closes_the_gap() {
+	let mut bv = bitvec![0, 1, 0, 1, 1, 0];
+	bv.drain(1 .. 4).next();
+	assert_eq!(bv, bits![0, 1, 0]);
+}
+
+#[test]
+fn splice_replaces_a_range_of_different_length() {
+	let mut bv = bitvec![0, 1, 0, 1, 1, 0];
+	let removed =
+		bv.splice(1 .. 4, [true, true].iter().copied()).collect::<Vec<_>>();
+	assert_eq!(removed, [true, false, true]);
+	assert_eq!(bv, bits![0, 1, 1, 1, 0]);
+}
+
+#[test]
+fn into_iter_yields_every_bit_in_order() {
+	let bv = bitvec![0, 1, 1, 0];
+	assert_eq!(bv.into_iter().collect::<Vec<_>>(), [false, true, true, false]);
+}
+
+#[test]
+fn try_from_vec_preserves_capacity() {
+	let vec = Vec::<u8>::with_capacity(4);
+	let elements = vec.capacity();
+
+	let bv = BitVec::<Local, u8>::try_from_vec(vec).unwrap();
+
+	assert_eq!(bv.capacity(), elements * 8);
+}
+
+#[test]
+fn try_from_vec_reports_capacity_overflow() {
+	use core::ptr::NonNull;
+
+	// `vec.len() * 8` must overflow `usize` without ever being read, since
+	// this `Vec` has no real backing allocation.
+	let len = usize::MAX / 8 + 1;
+	let vec = unsafe {
+		Vec::from_raw_parts(NonNull::<u8>::dangling().as_ptr(), len, len)
+	};
+
+	assert!(BitVec::<Local, u8>::try_from_vec(vec).is_err());
+}